@@ -0,0 +1,87 @@
+//! Daisy-chain support
+
+use crate::device_impl::CheckChannel;
+use crate::interface::{self, ChainWriteCommand};
+use crate::{Channel, Command, Error};
+use core::marker::PhantomData;
+
+/// Driver for a daisy-chain of `N` MCP4x devices sharing a single chip-select.
+///
+/// The devices are wired SI/SO to SI/SO as described in the datasheet, so a
+/// single SPI transaction shifts one 2-byte command through every device in
+/// the chain. `device_index` `0` is the device whose SO pin is connected to
+/// the host's MISO (i.e. the last device the data passes through).
+///
+/// This goes through [`interface::ChainWriteCommand`] rather than
+/// [`interface::WriteCommand`]: the latter's `write_command(command, data)`
+/// only frames a single 2-byte transaction, which cannot express `N`
+/// concatenated frames shifted through in one SPI transaction, so the chain
+/// gets its own narrow trait on the same `interface` module instead of
+/// talking to `SpiDevice` directly.
+#[derive(Debug)]
+pub struct Mcp4xChain<DI, IC, const N: usize> {
+    iface: DI,
+    _ic: PhantomData<IC>,
+}
+
+impl<SPI, IC, const N: usize> Mcp4xChain<interface::SpiInterface<SPI>, IC, N> {
+    /// Create a new driver instance for a chain of `N` devices.
+    pub fn new(spi: SPI) -> Self {
+        Mcp4xChain {
+            iface: interface::SpiInterface { spi },
+            _ic: PhantomData,
+        }
+    }
+
+    /// Destroy driver instance, return SPI bus instance.
+    pub fn destroy(self) -> SPI {
+        self.iface.spi
+    }
+}
+
+impl<DI, IC, E, const N: usize> Mcp4xChain<DI, IC, N>
+where
+    DI: ChainWriteCommand<Error = Error<E>>,
+    IC: CheckChannel<E>,
+{
+    /// Set a channel of the device at `device_index` to a position.
+    ///
+    /// The remaining devices in the chain are sent a no-op frame so that
+    /// their wiper settings are left untouched. Will return
+    /// `Error::NotConfigured` if `device_index` is out of range for this
+    /// chain, or `Error::WrongChannel` if the channel provided is not
+    /// available on the device.
+    pub fn set_position(
+        &mut self,
+        device_index: usize,
+        channel: Channel,
+        position: u8,
+    ) -> Result<(), Error<E>> {
+        IC::check_if_channel_is_appropriate(channel)?;
+        self.send(device_index, Command::SetPosition(channel, position))
+    }
+
+    /// Shutdown a channel of the device at `device_index`.
+    ///
+    /// The remaining devices in the chain are sent a no-op frame so that
+    /// their wiper settings are left untouched. Will return
+    /// `Error::NotConfigured` if `device_index` is out of range for this
+    /// chain, or `Error::WrongChannel` if the channel provided is not
+    /// available on the device.
+    pub fn shutdown(&mut self, device_index: usize, channel: Channel) -> Result<(), Error<E>> {
+        IC::check_if_channel_is_appropriate(channel)?;
+        self.send(device_index, Command::Shutdown(channel))
+    }
+
+    fn send(&mut self, device_index: usize, command: Command) -> Result<(), Error<E>> {
+        if device_index >= N {
+            return Err(Error::NotConfigured);
+        }
+        let mut frames = [[
+            Command::Nop.get_command_byte(),
+            Command::Nop.get_data_byte(),
+        ]; N];
+        frames[device_index] = [command.get_command_byte(), command.get_data_byte()];
+        self.iface.write_chain_command(frames)
+    }
+}
@@ -7,6 +7,9 @@ pub(crate) enum Command {
     SetPosition(Channel, u8),
     /// Shutdown channel
     Shutdown(Channel),
+    /// No-op, used to hold the wiper of a device while shifting a command
+    /// through to another device further down a daisy-chain
+    Nop,
 }
 
 impl Command {
@@ -14,12 +17,13 @@ impl Command {
         match *self {
             Command::SetPosition(channel, _) => 0b0001_0000 | channel.get_bits(),
             Command::Shutdown(channel) => 0b0010_0000 | channel.get_bits(),
+            Command::Nop => 0,
         }
     }
     pub(crate) fn get_data_byte(&self) -> u8 {
         match *self {
             Command::SetPosition(_, position) => position,
-            Command::Shutdown(_) => 0,
+            Command::Shutdown(_) | Command::Nop => 0,
         }
     }
 }
@@ -55,4 +59,11 @@ mod tests {
 
     shutdown!(can_shutdown_ch_0, Ch0, 0b0010_0001);
     shutdown!(can_shutdown_ch_1, Ch1, 0b0010_0010);
+
+    #[test]
+    fn nop_is_all_zero() {
+        let cmd = Command::Nop;
+        assert_eq!(0, cmd.get_command_byte());
+        assert_eq!(0, cmd.get_data_byte());
+    }
 }
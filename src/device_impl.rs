@@ -1,7 +1,14 @@
 //! Device implementation
 
+#[cfg(feature = "async")]
+use crate::AsyncMcp4x;
 use crate::{ic, interface, private, Channel, Command, Error, Mcp4x};
 use core::marker::PhantomData;
+use embedded_hal::delay::DelayNs;
+use embedded_hal::digital::OutputPin;
+
+/// Minimum RS pulse width, in microseconds, to trigger a hardware reset (see datasheet).
+const RESET_PULSE_WIDTH_US: u32 = 1;
 
 #[doc(hidden)]
 pub trait CheckChannel<CommE>: private::Sealed {
@@ -24,7 +31,17 @@ impl<CommE> CheckChannel<CommE> for ic::Mcp42x {
     }
 }
 
-impl<DI, IC, CommE> Mcp4x<DI, IC>
+/// Index into the shadow `positions` array for a given channel. `Channel::All`
+/// shares index 0 with `Channel::Ch0` since `set_position()` always keeps
+/// both shadow entries in sync when writing to all channels.
+fn shadow_index(channel: Channel) -> usize {
+    match channel {
+        Channel::Ch1 => 1,
+        Channel::Ch0 | Channel::All => 0,
+    }
+}
+
+impl<DI, IC, SHDN, RS, CommE> Mcp4x<DI, IC, SHDN, RS>
 where
     DI: interface::WriteCommand<Error = Error<CommE>>,
     IC: CheckChannel<CommE>,
@@ -37,7 +54,13 @@ where
         IC::check_if_channel_is_appropriate(channel)?;
         let cmd = Command::SetPosition(channel, position);
         self.iface
-            .write_command(cmd.get_command_byte(), cmd.get_data_byte())
+            .write_command(cmd.get_command_byte(), cmd.get_data_byte())?;
+        if channel == Channel::All {
+            self.positions = [Some(position); 2];
+        } else {
+            self.positions[shadow_index(channel)] = Some(position);
+        }
+        Ok(())
     }
 
     /// Shutdown a channel.
@@ -50,6 +73,126 @@ where
         self.iface
             .write_command(cmd.get_command_byte(), cmd.get_data_byte())
     }
+
+    /// Get the last wiper position written to `channel`, read back from a
+    /// software shadow register since these devices cannot be read over SPI.
+    ///
+    /// Returns `None` if `channel` is not available on this device, or if
+    /// `set_position()` (or an equivalent, such as
+    /// [`set_ratio()`](#method.set_ratio)) has not been called for this
+    /// channel yet, unless [`assume_power_on_state()`](#method.assume_power_on_state)
+    /// was used to seed it with the documented power-up default.
+    pub fn get_position(&self, channel: Channel) -> Option<u8> {
+        IC::check_if_channel_is_appropriate(channel).ok()?;
+        self.positions[shadow_index(channel)]
+    }
+
+    /// Seed the shadow wiper positions with the documented power-up default
+    /// of mid-scale (0x80), assuming the device has not been written to
+    /// since it powered on.
+    pub fn assume_power_on_state(mut self) -> Self {
+        self.positions = [Some(0x80); 2];
+        self
+    }
+
+    /// Increment a channel's wiper position by `steps`, saturating at 255.
+    ///
+    /// Will return `Error::WrongChannel` if the channel provided is not
+    /// available on the device, and `Error::NotConfigured` if the current
+    /// position of `channel` is not known, see
+    /// [`get_position()`](#method.get_position).
+    pub fn increment(&mut self, channel: Channel, steps: u8) -> Result<(), Error<CommE>> {
+        IC::check_if_channel_is_appropriate(channel)?;
+        let position = self.get_position(channel).ok_or(Error::NotConfigured)?;
+        self.set_position(channel, position.saturating_add(steps))
+    }
+
+    /// Decrement a channel's wiper position by `steps`, saturating at 0.
+    ///
+    /// Will return `Error::WrongChannel` if the channel provided is not
+    /// available on the device, and `Error::NotConfigured` if the current
+    /// position of `channel` is not known, see
+    /// [`get_position()`](#method.get_position).
+    pub fn decrement(&mut self, channel: Channel, steps: u8) -> Result<(), Error<CommE>> {
+        IC::check_if_channel_is_appropriate(channel)?;
+        let position = self.get_position(channel).ok_or(Error::NotConfigured)?;
+        self.set_position(channel, position.saturating_sub(steps))
+    }
+}
+
+#[cfg(feature = "async")]
+impl<DI, IC, CommE> AsyncMcp4x<DI, IC>
+where
+    DI: interface::AsyncWriteCommand<Error = Error<CommE>>,
+    IC: CheckChannel<CommE>,
+{
+    /// Set a channel to a position.
+    ///
+    /// Will return `Error::WrongChannel` if the channel provided is not available
+    /// on the device.
+    pub async fn set_position(
+        &mut self,
+        channel: Channel,
+        position: u8,
+    ) -> Result<(), Error<CommE>> {
+        IC::check_if_channel_is_appropriate(channel)?;
+        let cmd = Command::SetPosition(channel, position);
+        self.iface
+            .write_command(cmd.get_command_byte(), cmd.get_data_byte())
+            .await?;
+        if channel == Channel::All {
+            self.positions = [Some(position); 2];
+        } else {
+            self.positions[shadow_index(channel)] = Some(position);
+        }
+        Ok(())
+    }
+
+    /// Shutdown a channel.
+    ///
+    /// Will return `Error::WrongChannel` if the channel provided is not available
+    /// on the device.
+    pub async fn shutdown(&mut self, channel: Channel) -> Result<(), Error<CommE>> {
+        IC::check_if_channel_is_appropriate(channel)?;
+        let cmd = Command::Shutdown(channel);
+        self.iface
+            .write_command(cmd.get_command_byte(), cmd.get_data_byte())
+            .await
+    }
+}
+
+#[cfg(feature = "async")]
+impl<SPI> AsyncMcp4x<interface::SpiInterface<SPI>, ic::Mcp41x> {
+    /// Create new MCP41x device instance
+    pub fn new_mcp41x(spi: SPI) -> Self {
+        AsyncMcp4x {
+            iface: interface::SpiInterface { spi },
+            _ic: PhantomData,
+            positions: [None, None],
+        }
+    }
+
+    /// Destroy driver instance, return SPI bus instance.
+    pub fn destroy_mcp41x(self) -> SPI {
+        self.iface.spi
+    }
+}
+
+#[cfg(feature = "async")]
+impl<SPI> AsyncMcp4x<interface::SpiInterface<SPI>, ic::Mcp42x> {
+    /// Create new MCP42x device instance
+    pub fn new_mcp42x(spi: SPI) -> Self {
+        AsyncMcp4x {
+            iface: interface::SpiInterface { spi },
+            _ic: PhantomData,
+            positions: [None, None],
+        }
+    }
+
+    /// Destroy driver instance, return SPI bus instance.
+    pub fn destroy_mcp42x(self) -> SPI {
+        self.iface.spi
+    }
 }
 
 impl<SPI> Mcp4x<interface::SpiInterface<SPI>, ic::Mcp41x> {
@@ -58,26 +201,158 @@ impl<SPI> Mcp4x<interface::SpiInterface<SPI>, ic::Mcp41x> {
         Mcp4x {
             iface: interface::SpiInterface { spi },
             _ic: PhantomData,
+            shdn: None,
+            rs: None,
+            r_ab: None,
+            positions: [None, None],
         }
     }
 
-    /// Destroy driver instance, return SPI bus instance and CS output pin.
+    /// Destroy driver instance, return SPI bus instance.
     pub fn destroy_mcp41x(self) -> SPI {
         self.iface.spi
     }
 }
 
+impl<SPI, SHDN, RS> Mcp4x<interface::SpiInterface<SPI>, ic::Mcp41x, SHDN, RS> {
+    /// Create new MCP41x device instance, with optional hardware SHDN and/or
+    /// RS (reset) pins.
+    pub fn new_mcp41x_with_pins(spi: SPI, shdn: Option<SHDN>, rs: Option<RS>) -> Self {
+        Mcp4x {
+            iface: interface::SpiInterface { spi },
+            _ic: PhantomData,
+            shdn,
+            rs,
+            r_ab: None,
+            positions: [None, None],
+        }
+    }
+}
+
 impl<SPI> Mcp4x<interface::SpiInterface<SPI>, ic::Mcp42x> {
     /// Create new MCP42x device instance
     pub fn new_mcp42x(spi: SPI) -> Self {
         Mcp4x {
             iface: interface::SpiInterface { spi },
             _ic: PhantomData,
+            shdn: None,
+            rs: None,
+            r_ab: None,
+            positions: [None, None],
         }
     }
 
-    /// Destroy driver instance, return SPI bus instance and CS output pin.
+    /// Destroy driver instance, return SPI bus instance.
     pub fn destroy_mcp42x(self) -> SPI {
         self.iface.spi
     }
 }
+
+impl<SPI, SHDN, RS> Mcp4x<interface::SpiInterface<SPI>, ic::Mcp42x, SHDN, RS> {
+    /// Create new MCP42x device instance, with optional hardware SHDN and/or
+    /// RS (reset) pins.
+    pub fn new_mcp42x_with_pins(spi: SPI, shdn: Option<SHDN>, rs: Option<RS>) -> Self {
+        Mcp4x {
+            iface: interface::SpiInterface { spi },
+            _ic: PhantomData,
+            shdn,
+            rs,
+            r_ab: None,
+            positions: [None, None],
+        }
+    }
+}
+
+impl<DI, IC, SHDN, RS, CommE, PinE> Mcp4x<DI, IC, SHDN, RS>
+where
+    DI: interface::WriteCommand<Error = Error<CommE>>,
+    SHDN: OutputPin<Error = PinE>,
+{
+    /// Enable or disable the hardware shutdown mode via the SHDN pin.
+    ///
+    /// While enabled, both channels are shut down in hardware the same way
+    /// [`shutdown()`](#method.shutdown) does it over SPI. Returns
+    /// `Error::NotConfigured` if no SHDN pin was provided to the constructor.
+    pub fn hardware_shutdown(&mut self, enable: bool) -> Result<(), Error<CommE, PinE>> {
+        match &mut self.shdn {
+            // SHDN is active-low: driving it low enables hardware shutdown.
+            Some(shdn) if enable => shdn.set_low().map_err(Error::Pin),
+            Some(shdn) => shdn.set_high().map_err(Error::Pin),
+            None => Err(Error::NotConfigured),
+        }
+    }
+}
+
+impl<DI, IC, SHDN, RS, CommE, PinE> Mcp4x<DI, IC, SHDN, RS>
+where
+    DI: interface::WriteCommand<Error = Error<CommE>>,
+    RS: OutputPin<Error = PinE>,
+{
+    /// Perform a hardware reset via the RS pin, returning the wiper(s) to
+    /// the mid-scale position (0x80). The shadow register is updated to
+    /// match, just as [`assume_power_on_state()`](#method.assume_power_on_state) does.
+    ///
+    /// Returns `Error::NotConfigured` if no RS pin was provided to the
+    /// constructor.
+    pub fn hardware_reset<D: DelayNs>(&mut self, delay: &mut D) -> Result<(), Error<CommE, PinE>> {
+        match &mut self.rs {
+            Some(rs) => {
+                rs.set_low().map_err(Error::Pin)?;
+                delay.delay_us(RESET_PULSE_WIDTH_US);
+                rs.set_high().map_err(Error::Pin)?;
+                self.positions = [Some(0x80); 2];
+                Ok(())
+            }
+            None => Err(Error::NotConfigured),
+        }
+    }
+}
+
+impl<DI, IC, SHDN, RS> Mcp4x<DI, IC, SHDN, RS> {
+    /// Configure the full-scale (terminal A to terminal B) resistance of the
+    /// device, in ohms. See the [`resistance`](crate::resistance) module for
+    /// the values offered by this device family.
+    ///
+    /// This must be called before using [`set_resistance()`](#method.set_resistance);
+    /// [`set_ratio()`](#method.set_ratio) does not need it.
+    pub fn with_resistance(mut self, r_ab: u32) -> Self {
+        self.r_ab = Some(r_ab);
+        self
+    }
+}
+
+impl<DI, IC, SHDN, RS, CommE> Mcp4x<DI, IC, SHDN, RS>
+where
+    DI: interface::WriteCommand<Error = Error<CommE>>,
+    IC: CheckChannel<CommE>,
+{
+    /// Set a channel's wiper so that the resistance between the wiper and
+    /// terminal B is approximately `ohms`, given the full-scale resistance
+    /// configured via [`with_resistance()`](#method.with_resistance).
+    ///
+    /// This computes the wiper position as a plain `ohms / r_ab` ratio and
+    /// does not account for the device's nominal wiper resistance (a few
+    /// tens of ohms per the datasheet, and not specified precisely enough to
+    /// bake in a single constant across the 10/50/100 kΩ variants), so the
+    /// actual resistance will be offset by that amount, most noticeably for
+    /// small `ohms` values.
+    ///
+    /// `ohms` is clamped to the device's valid `0..=r_ab` range. Returns
+    /// `Error::NotConfigured` if [`with_resistance()`](#method.with_resistance)
+    /// was not called.
+    pub fn set_resistance(&mut self, channel: Channel, ohms: u32) -> Result<(), Error<CommE>> {
+        let r_ab = self.r_ab.ok_or(Error::NotConfigured)?;
+        self.set_ratio(channel, ohms as f32 / r_ab as f32)
+    }
+
+    /// Set a channel's wiper to the given ratio of its full travel, with
+    /// `0.0` corresponding to terminal B and `1.0` to terminal A.
+    ///
+    /// `ratio` is clamped to the `0.0..=1.0` range.
+    pub fn set_ratio(&mut self, channel: Channel, ratio: f32) -> Result<(), Error<CommE>> {
+        // `f32::round()` is std-only and unavailable in this `#![no_std]` crate,
+        // so round to the nearest step by adding 0.5 before the truncating cast.
+        let position = ((ratio.clamp(0.0, 1.0) * 255.0) + 0.5) as u8;
+        self.set_position(channel, position)
+    }
+}
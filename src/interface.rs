@@ -1,7 +1,9 @@
 //! SPI interface
 
 use crate::{private, Error};
-use embedded_hal::spi::SpiDevice;
+use embedded_hal::spi::{Operation, SpiDevice};
+#[cfg(feature = "async")]
+use embedded_hal_async::spi::SpiDevice as AsyncSpiDevice;
 
 /// SPI interface
 #[derive(Debug, Default)]
@@ -31,3 +33,56 @@ where
         result
     }
 }
+
+/// Perform a daisy-chained command, used by [`Mcp4xChain`](crate::Mcp4xChain)
+pub trait ChainWriteCommand: private::Sealed {
+    /// Error type
+    type Error;
+
+    /// Write `N` concatenated 2-byte command frames in a single SPI
+    /// transaction, shifting each frame through to its corresponding
+    /// device in the chain.
+    fn write_chain_command<const N: usize>(
+        &mut self,
+        frames: [[u8; 2]; N],
+    ) -> Result<(), Self::Error>;
+}
+
+impl<SPI, E> ChainWriteCommand for SpiInterface<SPI>
+where
+    SPI: SpiDevice<Error = E>,
+{
+    type Error = Error<E>;
+
+    fn write_chain_command<const N: usize>(
+        &mut self,
+        frames: [[u8; 2]; N],
+    ) -> Result<(), Error<E>> {
+        let mut operations: [Operation<'_, u8>; N] =
+            core::array::from_fn(|i| Operation::Write(&frames[i][..]));
+        self.spi.transaction(&mut operations).map_err(Error::Comm)
+    }
+}
+
+/// Perform a command asynchronously
+#[cfg(feature = "async")]
+pub trait AsyncWriteCommand: private::Sealed {
+    /// Error type
+    type Error;
+
+    /// Command
+    async fn write_command(&mut self, command: u8, data: u8) -> Result<(), Self::Error>;
+}
+
+#[cfg(feature = "async")]
+impl<SPI, E> AsyncWriteCommand for SpiInterface<SPI>
+where
+    SPI: AsyncSpiDevice<Error = E>,
+{
+    type Error = Error<E>;
+
+    async fn write_command(&mut self, command: u8, data: u8) -> Result<(), Error<E>> {
+        let payload: [u8; 2] = [command, data];
+        self.spi.write(&payload).await.map_err(Error::Comm)
+    }
+}
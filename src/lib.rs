@@ -108,6 +108,87 @@
 //! mcp42x.shutdown(Channel::Ch0).unwrap();
 //! ```
 //!
+//! ### Set a position asynchronously (requires the `async` feature)
+//!
+//! ```ignore
+//! use mcp4x::{AsyncMcp4x, Channel};
+//!
+//! async fn set_position(spi: impl embedded_hal_async::spi::SpiDevice) {
+//!     let mut mcp41x = AsyncMcp4x::new_mcp41x(spi);
+//!     mcp41x.set_position(Channel::Ch0, 125).await.unwrap();
+//! }
+//! ```
+//!
+//! ### Set a position in one of two daisy-chained MCP42x devices
+//!
+//! ```no_run
+//! use mcp4x::{ic, Channel, Mcp4xChain};
+//! use linux_embedded_hal::{Delay, SpidevBus, SysfsPin};
+//! use embedded_hal_bus::spi::ExclusiveDevice;
+//!
+//! let spi = SpidevBus::open("/dev/spidev0.0").unwrap();
+//! let chip_select = SysfsPin::new(25);
+//! let dev = ExclusiveDevice::new(spi, chip_select, Delay);
+//!
+//! let mut chain: Mcp4xChain<_, ic::Mcp42x, 2> = Mcp4xChain::new(dev);
+//! chain.set_position(1, Channel::Ch0, 125).unwrap();
+//! ```
+//!
+//! ### Drive the hardware SHDN and RS pins
+//!
+//! ```no_run
+//! use mcp4x::Mcp4x;
+//! use linux_embedded_hal::{Delay, SpidevBus, SysfsPin};
+//! use embedded_hal_bus::spi::ExclusiveDevice;
+//!
+//! let spi = SpidevBus::open("/dev/spidev0.0").unwrap();
+//! let chip_select = SysfsPin::new(25);
+//! let dev = ExclusiveDevice::new(spi, chip_select, Delay);
+//!
+//! let shdn = SysfsPin::new(23);
+//! let rs = SysfsPin::new(24);
+//! let mut mcp42x = Mcp4x::new_mcp42x_with_pins(dev, Some(shdn), Some(rs));
+//!
+//! mcp42x.hardware_shutdown(true).unwrap();
+//! mcp42x.hardware_shutdown(false).unwrap();
+//! mcp42x.hardware_reset(&mut Delay).unwrap();
+//! ```
+//!
+//! ### Set a channel by resistance or ratio instead of raw position
+//!
+//! ```no_run
+//! use mcp4x::{resistance, Channel, Mcp4x};
+//! use linux_embedded_hal::{Delay, SpidevBus, SysfsPin};
+//! use embedded_hal_bus::spi::ExclusiveDevice;
+//!
+//! let spi = SpidevBus::open("/dev/spidev0.0").unwrap();
+//! let chip_select = SysfsPin::new(25);
+//! let dev = ExclusiveDevice::new(spi, chip_select, Delay);
+//!
+//! let mut mcp41x = Mcp4x::new_mcp41x(dev).with_resistance(resistance::R_10K);
+//!
+//! mcp41x.set_resistance(Channel::Ch0, 2_500).unwrap();
+//! mcp41x.set_ratio(Channel::Ch0, 0.25).unwrap();
+//! ```
+//!
+//! ### Read back the last wiper position and adjust it relatively
+//!
+//! ```no_run
+//! use mcp4x::{Channel, Mcp4x};
+//! use linux_embedded_hal::{Delay, SpidevBus, SysfsPin};
+//! use embedded_hal_bus::spi::ExclusiveDevice;
+//!
+//! let spi = SpidevBus::open("/dev/spidev0.0").unwrap();
+//! let chip_select = SysfsPin::new(25);
+//! let dev = ExclusiveDevice::new(spi, chip_select, Delay);
+//!
+//! let mut mcp41x = Mcp4x::new_mcp41x(dev).assume_power_on_state();
+//!
+//! assert_eq!(Some(0x80), mcp41x.get_position(Channel::Ch0));
+//! mcp41x.increment(Channel::Ch0, 10).unwrap();
+//! mcp41x.decrement(Channel::Ch0, 5).unwrap();
+//! ```
+//!
 
 #![deny(unsafe_code)]
 #![deny(missing_docs)]
@@ -119,13 +200,18 @@ use embedded_hal::spi::{Mode, MODE_0};
 
 /// All possible errors in this crate
 #[derive(Debug)]
-pub enum Error<CommE, PinE> {
+pub enum Error<CommE, PinE = core::convert::Infallible> {
     /// Communication error
     Comm(CommE),
     /// Pin error
     Pin(PinE),
     /// Wrong channel for this device provided
     WrongChannel,
+    /// The operation requires something the driver does not have: an
+    /// optional hardware pin or full-scale resistance that was not
+    /// configured, a known wiper position for a relative adjustment, or a
+    /// device index within the daisy-chain
+    NotConfigured,
 }
 
 /// SPI mode
@@ -162,15 +248,63 @@ pub mod ic {
 }
 
 /// MCP4x digital potentiometer driver
+///
+/// `SHDN` and `RS` are the optional hardware shutdown and reset pin types.
+/// They default to `()`, meaning no hardware pin is wired, in which case
+/// [`hardware_shutdown()`] and [`hardware_reset()`] are unavailable.
+///
+/// [`hardware_shutdown()`]: struct.Mcp4x.html#method.hardware_shutdown
+/// [`hardware_reset()`]: struct.Mcp4x.html#method.hardware_reset
+#[derive(Debug, Default)]
+pub struct Mcp4x<DI, IC, SHDN = (), RS = ()> {
+    iface: DI,
+    _ic: PhantomData<IC>,
+    shdn: Option<SHDN>,
+    rs: Option<RS>,
+    r_ab: Option<u32>,
+    positions: [Option<u8>; 2],
+}
+
+/// MCP4x digital potentiometer driver, built on an async SPI interface.
+///
+/// This mirrors [`Mcp4x`]'s `set_position()`/`shutdown()` API, but driven by [`interface::AsyncWriteCommand`] instead of
+/// [`interface::WriteCommand`], so those methods are `async fn`s. The two
+/// are kept as distinct types rather than two inherent impls on the same
+/// struct: Rust's coherence checker cannot prove that a `DI` implementing
+/// one of the two traits could never also implement the other, so
+/// identically-named sync and async methods on the same generic struct
+/// would conflict.
+///
+/// Unlike [`Mcp4x`], it has no hardware SHDN/RS pin control and no
+/// resistance/ratio helpers, so it carries no `SHDN`/`RS`/`r_ab` fields. It
+/// records each written wiper position internally but offers no
+/// `get_position()`, `increment()` or `decrement()`.
+#[cfg(feature = "async")]
 #[derive(Debug, Default)]
-pub struct Mcp4x<DI, IC> {
+pub struct AsyncMcp4x<DI, IC> {
     iface: DI,
     _ic: PhantomData<IC>,
+    positions: [Option<u8>; 2],
+}
+
+/// Full-scale (terminal A to terminal B) resistance values offered by the
+/// MCP41XXX/MCP42XXX family, in ohms. Pass one of these to
+/// [`Mcp4x::with_resistance()`](struct.Mcp4x.html#method.with_resistance).
+pub mod resistance {
+    /// 10 kΩ devices (MCP41010, MCP42010)
+    pub const R_10K: u32 = 10_000;
+    /// 50 kΩ devices (MCP41050, MCP42050)
+    pub const R_50K: u32 = 50_000;
+    /// 100 kΩ devices (MCP41100, MCP42100)
+    pub const R_100K: u32 = 100_000;
 }
 
 mod device_impl;
 pub use crate::device_impl::CheckChannel;
 
+mod chain;
+pub use crate::chain::Mcp4xChain;
+
 mod commands;
 use crate::commands::Command;
 
@@ -181,7 +315,7 @@ mod private {
     use super::{ic, interface};
     pub trait Sealed {}
 
-    impl<SPI, CS> Sealed for interface::SpiInterface<SPI, CS> {}
+    impl<SPI> Sealed for interface::SpiInterface<SPI> {}
     impl Sealed for ic::Mcp41x {}
     impl Sealed for ic::Mcp42x {}
 }
@@ -0,0 +1,55 @@
+use embedded_hal_mock::eh1::spi::{Mock as SpiMock, Transaction as SpiTrans};
+use mcp4x::{ic, interface, Channel, Mcp4xChain};
+
+fn new_chain<const N: usize>(
+    transactions: &[SpiTrans<u8>],
+) -> Mcp4xChain<interface::SpiInterface<SpiMock<u8>>, ic::Mcp41x, N> {
+    let mut wrapped = vec![SpiTrans::transaction_start()];
+    wrapped.extend(transactions.iter().cloned());
+    wrapped.push(SpiTrans::transaction_end());
+    Mcp4xChain::new(SpiMock::new(&wrapped))
+}
+
+#[test]
+fn can_set_position_of_first_device_in_chain() {
+    let trans = [
+        SpiTrans::write_vec(vec![0b0001_0001, 50]),
+        SpiTrans::write_vec(vec![0, 0]),
+    ];
+    let mut chain = new_chain::<2>(&trans);
+    chain.set_position(0, Channel::Ch0, 50).unwrap();
+    chain.destroy().done();
+}
+
+#[test]
+fn can_set_position_of_second_device_in_chain() {
+    let trans = [
+        SpiTrans::write_vec(vec![0, 0]),
+        SpiTrans::write_vec(vec![0b0001_0001, 50]),
+    ];
+    let mut chain = new_chain::<2>(&trans);
+    chain.set_position(1, Channel::Ch0, 50).unwrap();
+    chain.destroy().done();
+}
+
+#[test]
+fn can_shutdown_device_in_chain() {
+    let trans = [
+        SpiTrans::write_vec(vec![0, 0]),
+        SpiTrans::write_vec(vec![0b0010_0001, 0]),
+    ];
+    let mut chain = new_chain::<2>(&trans);
+    chain.shutdown(1, Channel::Ch0).unwrap();
+    chain.destroy().done();
+}
+
+#[test]
+fn set_position_cannot_provide_out_of_range_device_index() {
+    let mut chain: Mcp4xChain<interface::SpiInterface<SpiMock<u8>>, ic::Mcp41x, 2> =
+        Mcp4xChain::new(SpiMock::new(&[]));
+    match chain.set_position(2, Channel::Ch0, 50) {
+        Err(mcp4x::Error::NotConfigured) => (),
+        _ => panic!("Out of range device index not reported."),
+    }
+    chain.destroy().done();
+}
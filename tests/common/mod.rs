@@ -0,0 +1,19 @@
+//! Fixtures shared by the integration test binaries in this crate.
+
+use embedded_hal_mock::eh1::spi::Transaction as SpiTrans;
+
+/// Wrap each transaction in a `transaction_start()`/`transaction_end()` pair,
+/// matching the per-call SPI transaction framing that `WriteCommand` and
+/// `AsyncWriteCommand` implementations use.
+pub fn wrap_transactions(transactions: &[SpiTrans<u8>]) -> Vec<SpiTrans<u8>> {
+    transactions
+        .iter()
+        .flat_map(|trans| {
+            [
+                SpiTrans::transaction_start(),
+                trans.clone(),
+                SpiTrans::transaction_end(),
+            ]
+        })
+        .collect()
+}
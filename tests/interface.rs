@@ -1,22 +1,15 @@
 use embedded_hal_mock::eh1::spi::{Mock as SpiMock, Transaction as SpiTrans};
 use mcp4x::{ic, interface, Channel, Error, Mcp4x};
 
+mod common;
+use common::wrap_transactions;
+
 macro_rules! device_support {
     ($create:ident, $destroy:ident, $ic:ident) => {
         pub fn $create(
             transactions: &[SpiTrans<u8>],
         ) -> Mcp4x<interface::SpiInterface<SpiMock<u8>>, ic::$ic> {
-            let wrapped: Vec<SpiTrans<u8>> = transactions
-                .iter()
-                .flat_map(|trans| {
-                    [
-                        SpiTrans::transaction_start(),
-                        trans.clone(),
-                        SpiTrans::transaction_end(),
-                    ]
-                })
-                .collect();
-            Mcp4x::$create(SpiMock::new(&wrapped))
+            Mcp4x::$create(SpiMock::new(&wrap_transactions(transactions)))
         }
 
         pub fn $destroy(dev: Mcp4x<interface::SpiInterface<SpiMock<u8>>, ic::$ic>) {
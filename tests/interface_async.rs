@@ -0,0 +1,28 @@
+#![cfg(feature = "async")]
+use embedded_hal_mock::eh1::spi::{Mock as SpiMock, Transaction as SpiTrans};
+use mcp4x::{ic, interface, AsyncMcp4x, Channel};
+
+mod common;
+use common::wrap_transactions;
+
+async fn new_mcp41x(
+    transactions: &[SpiTrans<u8>],
+) -> AsyncMcp4x<interface::SpiInterface<SpiMock<u8>>, ic::Mcp41x> {
+    AsyncMcp4x::new_mcp41x(SpiMock::new(&wrap_transactions(transactions)))
+}
+
+#[tokio::test]
+async fn can_set_position_async() {
+    let trans = [SpiTrans::write_vec(vec![0b0001_0001, 50])];
+    let mut dev = new_mcp41x(&trans).await;
+    dev.set_position(Channel::Ch0, 50).await.unwrap();
+    dev.destroy_mcp41x().done();
+}
+
+#[tokio::test]
+async fn can_shutdown_async() {
+    let trans = [SpiTrans::write_vec(vec![0b0010_0001, 0])];
+    let mut dev = new_mcp41x(&trans).await;
+    dev.shutdown(Channel::Ch0).await.unwrap();
+    dev.destroy_mcp41x().done();
+}
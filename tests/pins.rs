@@ -0,0 +1,83 @@
+use embedded_hal_mock::eh1::{
+    delay::NoopDelay,
+    digital::{Mock as PinMock, State as PinState, Transaction as PinTrans},
+    spi::{Mock as SpiMock, Transaction as SpiTrans},
+};
+use mcp4x::{interface, Channel, Error, Mcp4x};
+
+mod common;
+use common::wrap_transactions;
+
+fn new_mcp42x_with_pins(
+    shdn: &[PinTrans],
+    rs: &[PinTrans],
+) -> Mcp4x<interface::SpiInterface<SpiMock<u8>>, mcp4x::ic::Mcp42x, PinMock, PinMock> {
+    new_mcp42x_with_pins_and_spi(shdn, rs, &[])
+}
+
+fn new_mcp42x_with_pins_and_spi(
+    shdn: &[PinTrans],
+    rs: &[PinTrans],
+    spi_transactions: &[SpiTrans<u8>],
+) -> Mcp4x<interface::SpiInterface<SpiMock<u8>>, mcp4x::ic::Mcp42x, PinMock, PinMock> {
+    Mcp4x::new_mcp42x_with_pins(
+        SpiMock::new(&wrap_transactions(spi_transactions)),
+        Some(PinMock::new(shdn)),
+        Some(PinMock::new(rs)),
+    )
+}
+
+#[test]
+fn hardware_shutdown_drives_shdn_low_to_enable() {
+    let mut dev = new_mcp42x_with_pins(&[PinTrans::set(PinState::Low)], &[]);
+    dev.hardware_shutdown(true).unwrap();
+}
+
+#[test]
+fn hardware_shutdown_drives_shdn_high_to_disable() {
+    let mut dev = new_mcp42x_with_pins(&[PinTrans::set(PinState::High)], &[]);
+    dev.hardware_shutdown(false).unwrap();
+}
+
+#[test]
+fn hardware_reset_pulses_rs_low_then_high() {
+    let mut dev = new_mcp42x_with_pins(
+        &[],
+        &[PinTrans::set(PinState::Low), PinTrans::set(PinState::High)],
+    );
+    dev.hardware_reset(&mut NoopDelay::new()).unwrap();
+}
+
+#[test]
+fn hardware_reset_seeds_shadow_register_mid_scale() {
+    let mut dev = new_mcp42x_with_pins(
+        &[],
+        &[PinTrans::set(PinState::Low), PinTrans::set(PinState::High)],
+    );
+    dev.hardware_reset(&mut NoopDelay::new()).unwrap();
+    assert_eq!(Some(0x80), dev.get_position(Channel::Ch0));
+    assert_eq!(Some(0x80), dev.get_position(Channel::Ch1));
+}
+
+#[test]
+fn increment_after_hardware_reset_adjusts_from_mid_scale() {
+    let trans = [SpiTrans::write_vec(vec![0b0001_0001, 0x8a])];
+    let mut dev = new_mcp42x_with_pins_and_spi(
+        &[],
+        &[PinTrans::set(PinState::Low), PinTrans::set(PinState::High)],
+        &trans,
+    );
+    dev.hardware_reset(&mut NoopDelay::new()).unwrap();
+    dev.increment(Channel::Ch0, 10).unwrap();
+    assert_eq!(Some(0x8a), dev.get_position(Channel::Ch0));
+}
+
+#[test]
+fn hardware_shutdown_without_pin_is_not_configured() {
+    let mut dev: Mcp4x<_, mcp4x::ic::Mcp42x, PinMock, PinMock> =
+        Mcp4x::new_mcp42x_with_pins(SpiMock::<u8>::new(&[]), None, None);
+    match dev.hardware_shutdown(true) {
+        Err(Error::NotConfigured) => (),
+        _ => panic!("NotConfigured error not reported."),
+    }
+}
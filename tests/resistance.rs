@@ -0,0 +1,54 @@
+use embedded_hal_mock::eh1::spi::{Mock as SpiMock, Transaction as SpiTrans};
+use mcp4x::{resistance, Channel, Error, Mcp4x};
+
+mod common;
+use common::wrap_transactions;
+
+fn new_mcp41x(
+    transactions: &[SpiTrans<u8>],
+) -> Mcp4x<mcp4x::interface::SpiInterface<SpiMock<u8>>, mcp4x::ic::Mcp41x> {
+    Mcp4x::new_mcp41x(SpiMock::new(&wrap_transactions(transactions)))
+        .with_resistance(resistance::R_10K)
+}
+
+#[test]
+fn can_set_resistance() {
+    let trans = [SpiTrans::write_vec(vec![0b0001_0001, 128])];
+    let mut dev = new_mcp41x(&trans);
+    dev.set_resistance(Channel::Ch0, 5_000).unwrap();
+    dev.destroy_mcp41x().done();
+}
+
+#[test]
+fn resistance_above_full_scale_is_clamped() {
+    let trans = [SpiTrans::write_vec(vec![0b0001_0001, 255])];
+    let mut dev = new_mcp41x(&trans);
+    dev.set_resistance(Channel::Ch0, 20_000).unwrap();
+    dev.destroy_mcp41x().done();
+}
+
+#[test]
+fn can_set_ratio() {
+    let trans = [SpiTrans::write_vec(vec![0b0001_0001, 64])];
+    let mut dev = new_mcp41x(&trans);
+    dev.set_ratio(Channel::Ch0, 0.25).unwrap();
+    dev.destroy_mcp41x().done();
+}
+
+#[test]
+fn ratio_below_zero_is_clamped() {
+    let trans = [SpiTrans::write_vec(vec![0b0001_0001, 0])];
+    let mut dev = new_mcp41x(&trans);
+    dev.set_ratio(Channel::Ch0, -1.0).unwrap();
+    dev.destroy_mcp41x().done();
+}
+
+#[test]
+fn set_resistance_requires_configured_resistance() {
+    let mut dev = Mcp4x::new_mcp41x(SpiMock::<u8>::new(&[]));
+    match dev.set_resistance(Channel::Ch0, 1_000) {
+        Err(Error::NotConfigured) => (),
+        _ => panic!("NotConfigured error not reported."),
+    }
+    dev.destroy_mcp41x().done();
+}
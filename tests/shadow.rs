@@ -0,0 +1,118 @@
+use embedded_hal_mock::eh1::spi::{Mock as SpiMock, Transaction as SpiTrans};
+use mcp4x::{ic, interface, Channel, Error, Mcp4x};
+
+mod common;
+use common::wrap_transactions;
+
+fn new_mcp42x(
+    transactions: &[SpiTrans<u8>],
+) -> Mcp4x<interface::SpiInterface<SpiMock<u8>>, ic::Mcp42x> {
+    Mcp4x::new_mcp42x(SpiMock::new(&wrap_transactions(transactions)))
+}
+
+fn new_mcp41x(
+    transactions: &[SpiTrans<u8>],
+) -> Mcp4x<interface::SpiInterface<SpiMock<u8>>, ic::Mcp41x> {
+    Mcp4x::new_mcp41x(SpiMock::new(&wrap_transactions(transactions)))
+}
+
+#[test]
+fn position_is_unknown_before_first_write() {
+    let mut dev = new_mcp42x(&[]);
+    assert_eq!(None, dev.get_position(Channel::Ch0));
+    dev.destroy_mcp42x().done();
+}
+
+#[test]
+fn position_is_known_after_set_position() {
+    let trans = [SpiTrans::write_vec(vec![0b0001_0001, 50])];
+    let mut dev = new_mcp42x(&trans);
+    dev.set_position(Channel::Ch0, 50).unwrap();
+    assert_eq!(Some(50), dev.get_position(Channel::Ch0));
+    dev.destroy_mcp42x().done();
+}
+
+#[test]
+fn set_position_all_updates_both_shadow_entries() {
+    let trans = [SpiTrans::write_vec(vec![0b0001_0011, 50])];
+    let mut dev = new_mcp42x(&trans);
+    dev.set_position(Channel::All, 50).unwrap();
+    assert_eq!(Some(50), dev.get_position(Channel::Ch0));
+    assert_eq!(Some(50), dev.get_position(Channel::Ch1));
+    dev.destroy_mcp42x().done();
+}
+
+#[test]
+fn assume_power_on_state_seeds_mid_scale() {
+    let mut dev = new_mcp42x(&[]).assume_power_on_state();
+    assert_eq!(Some(0x80), dev.get_position(Channel::Ch0));
+    assert_eq!(Some(0x80), dev.get_position(Channel::Ch1));
+    dev.destroy_mcp42x().done();
+}
+
+#[test]
+fn can_increment_position() {
+    let trans = [SpiTrans::write_vec(vec![0b0001_0001, 0x8a])];
+    let mut dev = new_mcp42x(&trans).assume_power_on_state();
+    dev.increment(Channel::Ch0, 10).unwrap();
+    assert_eq!(Some(0x8a), dev.get_position(Channel::Ch0));
+    dev.destroy_mcp42x().done();
+}
+
+#[test]
+fn increment_saturates_at_max() {
+    let trans = [SpiTrans::write_vec(vec![0b0001_0001, 255])];
+    let mut dev = new_mcp42x(&trans).assume_power_on_state();
+    dev.increment(Channel::Ch0, 250).unwrap();
+    assert_eq!(Some(255), dev.get_position(Channel::Ch0));
+    dev.destroy_mcp42x().done();
+}
+
+#[test]
+fn can_decrement_position() {
+    let trans = [SpiTrans::write_vec(vec![0b0001_0001, 0x76])];
+    let mut dev = new_mcp42x(&trans).assume_power_on_state();
+    dev.decrement(Channel::Ch0, 10).unwrap();
+    assert_eq!(Some(0x76), dev.get_position(Channel::Ch0));
+    dev.destroy_mcp42x().done();
+}
+
+#[test]
+fn decrement_saturates_at_min() {
+    let trans = [SpiTrans::write_vec(vec![0b0001_0001, 0])];
+    let mut dev = new_mcp42x(&trans).assume_power_on_state();
+    dev.decrement(Channel::Ch0, 250).unwrap();
+    assert_eq!(Some(0), dev.get_position(Channel::Ch0));
+    dev.destroy_mcp42x().done();
+}
+
+#[test]
+fn increment_without_known_position_is_not_configured() {
+    let mut dev = new_mcp42x(&[]);
+    match dev.increment(Channel::Ch0, 1) {
+        Err(Error::NotConfigured) => (),
+        _ => panic!("NotConfigured error not reported."),
+    }
+    dev.destroy_mcp42x().done();
+}
+
+#[test]
+fn get_position_of_unavailable_channel_is_none_even_after_power_on_seed() {
+    let dev = new_mcp41x(&[]).assume_power_on_state();
+    assert_eq!(None, dev.get_position(Channel::Ch1));
+}
+
+#[test]
+fn increment_of_unavailable_channel_is_wrong_channel_regardless_of_shadow_state() {
+    let mut unseeded = new_mcp41x(&[]);
+    match unseeded.increment(Channel::Ch1, 1) {
+        Err(Error::WrongChannel) => (),
+        _ => panic!("WrongChannel error not reported."),
+    }
+
+    let mut seeded = new_mcp41x(&[]).assume_power_on_state();
+    match seeded.increment(Channel::Ch1, 1) {
+        Err(Error::WrongChannel) => (),
+        _ => panic!("WrongChannel error not reported."),
+    }
+}